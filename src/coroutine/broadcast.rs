@@ -0,0 +1,160 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use super::coro_param::ParamContext;
+use super::WaitingReason;
+
+struct Slot<T> {
+    seq: u64,
+    value: T,
+}
+
+/// A single slot a parked [`Recv`] future registers itself in, so it can be found and woken again
+/// without ever needing more than one entry per awaiter, no matter how many times it is polled.
+type WakerSlot = Arc<Mutex<Option<Waker>>>;
+
+struct Inner<T> {
+    capacity: usize,
+    ring: Vec<Option<Slot<T>>>,
+    next_seq: u64,
+    wakers: Vec<WakerSlot>,
+}
+
+/// A fixed-capacity, fan-out broadcast channel modeled on tokio's `broadcast`: every
+/// [`subscribe`][Broadcast::subscribe]r gets its own cursor into a ring buffer of sent values,
+/// and a slow subscriber that falls behind the buffer's capacity is fast-forwarded and told how
+/// many values it skipped, rather than silently losing them.
+#[derive(Clone)]
+pub struct Broadcast<T: Clone + Send + Sync + 'static>(Arc<Mutex<Inner<T>>>);
+
+impl<T: Clone + Send + Sync + 'static> Broadcast<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "a broadcast channel needs at least one slot");
+
+        Self(Arc::new(Mutex::new(Inner {
+            capacity,
+            ring: (0..capacity).map(|_| None).collect(),
+            next_seq: 0,
+            wakers: Vec::new(),
+        })))
+    }
+
+    /// Writes `value` into the next ring slot, overwriting the oldest one, and wakes every
+    /// parked subscriber.
+    pub fn send(&self, value: T) {
+        let mut inner = self.0.lock().unwrap();
+        let seq = inner.next_seq;
+        inner.next_seq += 1;
+        let idx = (seq as usize) % inner.capacity;
+        inner.ring[idx] = Some(Slot { seq, value });
+
+        for slot in inner.wakers.drain(..) {
+            if let Some(waker) = slot.lock().unwrap().take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Returns a new [`Receiver`] whose cursor starts at the next value sent after this call,
+    /// exactly like the upstream primitive.
+    pub(crate) fn subscribe(&self, context: ParamContext) -> Receiver<T> {
+        let cursor = self.0.lock().unwrap().next_seq;
+        Receiver {
+            context,
+            inner: self.0.clone(),
+            cursor,
+        }
+    }
+}
+
+/// What [`Receiver::recv`] resolves with.
+pub enum RecvResult<T> {
+    Value(T),
+    /// The subscriber fell further behind than the channel's capacity; the carried number of
+    /// values were dropped and the cursor was fast-forwarded to the oldest one still available.
+    Lagged(u64),
+}
+
+/// A subscription to a [`Broadcast`] channel, obtained via [`Broadcast::subscribe`] or
+/// [`Fib::subscribe`](super::function_coroutine::Fib::subscribe).
+pub struct Receiver<T: Clone + Send + Sync + 'static> {
+    context: ParamContext,
+    inner: Arc<Mutex<Inner<T>>>,
+    cursor: u64,
+}
+
+impl<T: Clone + Send + Sync + 'static> Receiver<T> {
+    /// Returns a future that resolves with the next value for this subscriber's cursor.
+    pub fn recv(&mut self) -> Recv<'_, T> {
+        Recv {
+            receiver: self,
+            slot: None,
+        }
+    }
+
+    /// An id unique to the channel's shared storage (stable across clones and subscribers), used
+    /// to tell apart distinct `Broadcast<T>` instances in [`WaitingReason::Broadcast`], which
+    /// can't carry a generic `T` and so can't be keyed on `TypeId` alone.
+    fn channel_id(&self) -> usize {
+        Arc::as_ptr(&self.inner) as usize
+    }
+}
+
+/// Future returned by [`Receiver::recv`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Recv<'a, T: Clone + Send + Sync + 'static> {
+    receiver: &'a mut Receiver<T>,
+    slot: Option<WakerSlot>,
+}
+
+impl<T: Clone + Send + Sync + 'static> Future for Recv<'_, T> {
+    type Output = RecvResult<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<RecvResult<T>> {
+        let this = self.get_mut();
+        let mut inner = this.receiver.inner.lock().unwrap();
+
+        let oldest = inner.next_seq.saturating_sub(inner.capacity as u64);
+        if this.receiver.cursor < oldest {
+            let skipped = oldest - this.receiver.cursor;
+            this.receiver.cursor = oldest;
+            return Poll::Ready(RecvResult::Lagged(skipped));
+        }
+
+        if this.receiver.cursor < inner.next_seq {
+            let idx = (this.receiver.cursor as usize) % inner.capacity;
+            let slot = inner.ring[idx]
+                .as_ref()
+                .expect("cursor within [oldest, next_seq) always has a slot");
+            debug_assert_eq!(slot.seq, this.receiver.cursor);
+            let value = slot.value.clone();
+            this.receiver.cursor += 1;
+            return Poll::Ready(RecvResult::Value(value));
+        }
+
+        // Register (or update) a single slot for this awaiter, rather than pushing a new waker
+        // on every poll: being re-polled every tick while still pending must not grow `wakers`
+        // without bound.
+        match &this.slot {
+            Some(slot) => *slot.lock().unwrap() = Some(cx.waker().clone()),
+            None => {
+                let slot: WakerSlot = Arc::new(Mutex::new(Some(cx.waker().clone())));
+                inner.wakers.push(slot.clone());
+                this.slot = Some(slot);
+            }
+        }
+        drop(inner);
+
+        let channel_id = this.receiver.channel_id();
+        this.receiver
+            .context
+            .yield_channel
+            .send(WaitingReason::Broadcast(channel_id));
+        Poll::Pending
+    }
+}
@@ -0,0 +1,157 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use bevy::prelude::Component;
+use bevy::prelude::Entity;
+use bevy::prelude::World;
+
+use super::coro_param::{CoroAccess, CoroParam, ParamContext};
+use super::WaitingReason;
+
+/// A single slot a parked [`Cancelled`] future registers itself in, so it can be found and woken
+/// again without ever needing more than one entry per awaiter, no matter how many times it is
+/// polled.
+type WakerSlot = Arc<Mutex<Option<Waker>>>;
+
+#[derive(Default)]
+struct State {
+    cancelled: bool,
+    children: Vec<CancellationToken>,
+}
+
+#[derive(Default)]
+struct Inner {
+    // Guards `cancelled` and `children` together so that a `cancel()` racing a `child_token()`
+    // can never observe a child registered after it decided the token wasn't cancelled yet.
+    state: Mutex<State>,
+    wakers: Mutex<Vec<WakerSlot>>,
+}
+
+/// A cooperative cancellation signal a coroutine subtree can share, mirroring
+/// `tokio_util::sync::CancellationToken`.
+///
+/// Unlike a losing branch of [`ParOr`](super::par_or::ParOr), which is simply dropped, a
+/// coroutine awaiting [`Fib::cancelled`](super::function_coroutine::Fib::cancelled) keeps
+/// running until it notices the token, so it can run teardown logic before returning.
+#[derive(Clone, Default, Component)]
+pub struct CancellationToken(Arc<Inner>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels this token, waking every coroutine parked on it and cascading to every
+    /// [`child_token`](Self::child_token) derived from it.
+    pub fn cancel(&self) {
+        let mut state = self.0.state.lock().unwrap();
+
+        if state.cancelled {
+            return;
+        }
+
+        state.cancelled = true;
+        let children = std::mem::take(&mut state.children);
+        drop(state);
+
+        for slot in self.0.wakers.lock().unwrap().drain(..) {
+            if let Some(waker) = slot.lock().unwrap().take() {
+                waker.wake();
+            }
+        }
+
+        for child in children {
+            child.cancel();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.state.lock().unwrap().cancelled
+    }
+
+    /// Returns a new token that is automatically cancelled as soon as `self` is, exactly like
+    /// the upstream primitive's child tokens.
+    pub fn child_token(&self) -> Self {
+        let child = Self::new();
+        let mut state = self.0.state.lock().unwrap();
+
+        if state.cancelled {
+            drop(state);
+            child.cancel();
+        } else {
+            state.children.push(child.clone());
+        }
+
+        child
+    }
+}
+
+impl CoroParam for CancellationToken {
+    fn init(context: ParamContext, world: &mut World, _access: &mut CoroAccess) -> Option<Self> {
+        let mut entity = world.get_entity_mut(context.owner)?;
+
+        if let Some(token) = entity.get::<CancellationToken>() {
+            Some(token.clone())
+        } else {
+            let token = CancellationToken::new();
+            entity.insert(token.clone());
+            Some(token)
+        }
+    }
+
+    fn is_valid(owner: Entity, world: &World) -> bool {
+        world.get_entity(owner).is_some()
+    }
+}
+
+/// Future returned by [`Fib::cancelled`](super::function_coroutine::Fib::cancelled).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Cancelled<'a> {
+    pub(crate) context: &'a ParamContext,
+    pub(crate) token: &'a CancellationToken,
+    slot: Option<WakerSlot>,
+}
+
+impl<'a> Cancelled<'a> {
+    pub(crate) fn new(context: &'a ParamContext, token: &'a CancellationToken) -> Self {
+        Self {
+            context,
+            token,
+            slot: None,
+        }
+    }
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        // Register (or update) a single slot for this awaiter, rather than pushing a new waker
+        // on every poll: being re-polled every tick while still pending must not grow `wakers`
+        // without bound.
+        match &this.slot {
+            Some(slot) => *slot.lock().unwrap() = Some(cx.waker().clone()),
+            None => {
+                let slot: WakerSlot = Arc::new(Mutex::new(Some(cx.waker().clone())));
+                this.token.0.wakers.lock().unwrap().push(slot.clone());
+                this.slot = Some(slot);
+            }
+        }
+
+        this.context
+            .yield_channel
+            .send(WaitingReason::Cancellation(this.token.clone()));
+        Poll::Pending
+    }
+}
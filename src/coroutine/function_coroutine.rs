@@ -9,13 +9,25 @@ use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 
+use super::broadcast::Broadcast;
+use super::broadcast::Receiver;
+use super::cancellation::CancellationToken;
+use super::cancellation::Cancelled;
 use super::coro_param::ParamContext;
 use super::coro_param::WorldWindow;
 use super::coro_param::{CoroAccess, CoroParam, YieldChannel};
 use super::duration::DurationFuture;
 use super::duration::NextTick;
 use super::par_and::ParAnd;
+use super::par_first::ParFirst;
 use super::par_or::ParOr;
+use super::semaphore::Acquire;
+use super::semaphore::CoroSemaphore;
+use super::shared;
+use super::shared::Join;
+use super::shared::SharedCoro;
+use super::signal::NextSignal;
+use super::signal::Signal;
 use super::UninitCoroutine;
 use super::{waker, Coroutine, CoroutineResult, WaitingReason};
 
@@ -42,7 +54,12 @@ impl<Marker: 'static, F> Coroutine for FunctionCoroutine<Marker, F>
 where
     F: CoroutineParamFunction<Marker>,
 {
-    fn resume(self: Pin<&mut Self>, world: &mut World) -> CoroutineResult<WaitingReason, ()> {
+    type Output = F::Output;
+
+    fn resume(
+        self: Pin<&mut Self>,
+        world: &mut World,
+    ) -> CoroutineResult<WaitingReason, F::Output> {
         let waker = waker::create();
         // Dummy context
         let mut cx = Context::from_waker(&waker);
@@ -51,7 +68,7 @@ where
         let res = this.world_window.scope(world, || this.future.poll(&mut cx));
 
         match res {
-            Poll::Ready(_) => CoroutineResult::Done(()),
+            Poll::Ready(output) => CoroutineResult::Done(output),
             Poll::Pending => {
                 CoroutineResult::Yield(this.yield_channel.receive().expect(ERR_WRONGAWAIT))
             }
@@ -68,7 +85,8 @@ where
 }
 
 pub trait CoroutineParamFunction<Marker>: Send + 'static {
-    type Future: Future<Output = ()> + Send + 'static;
+    type Output: Send + 'static;
+    type Future: Future<Output = Self::Output> + Send + 'static;
     type Params: CoroParam;
 
     fn init(self, params: Self::Params) -> Self::Future;
@@ -145,6 +163,73 @@ impl Fib {
     {
         ParAnd::new(self.context.clone()).with(coro)
     }
+
+    /// Returns a future that resolves once `token` is cancelled, letting the coroutine run
+    /// teardown logic before returning.
+    pub fn cancelled<'a>(&'a self, token: &'a CancellationToken) -> Cancelled<'a> {
+        Cancelled::new(&self.context, token)
+    }
+
+    /// Returns a future that resolves with the next value sent through `signal`, or immediately
+    /// if one is already waiting to be consumed.
+    pub fn next_signal<'a, T: Send + Sync + 'static>(
+        &'a self,
+        signal: &'a Signal<T>,
+    ) -> NextSignal<'a, T> {
+        NextSignal::new(&self.context, signal)
+    }
+
+    ///// Returns a coroutine that resolve once any of the underlying coroutine finishes, yielding
+    ///// the [`Winner`][super::par_first::Winner] (its index and output) rather than discarding it
+    ///// like [`par_or`](Self::par_or) does. The coroutine equivalent of `futures::select!`.
+    ///// Resolves with `None` if every branch passed to [`with`][ParFirst::with] failed to
+    ///// initialize.
+    pub fn par_first<C, Marker, O>(&self, coro: C) -> ParFirst<O>
+    where
+        C: UninitCoroutine<Marker>,
+        C::Coroutine: Coroutine<Output = O>,
+        O: Send + 'static,
+    {
+        ParFirst::new(self.context.clone()).with(coro)
+    }
+
+    /// Returns a future that resolves with a [`Permit`][super::semaphore::Permit] once `permits`
+    /// are free on `sem`, letting callers cap how many coroutines run a section of code
+    /// concurrently.
+    pub fn acquire<'a>(&'a self, sem: &CoroSemaphore, permits: u32) -> Acquire<'a> {
+        Acquire::new(&self.context, sem, permits)
+    }
+
+    /// Subscribes to a [`Broadcast`] channel, returning a [`Receiver`] whose cursor starts right
+    /// after this call (values sent before it are not replayed).
+    pub fn subscribe<T: Clone + Send + Sync + 'static>(
+        &self,
+        channel: &Broadcast<T>,
+    ) -> Receiver<T> {
+        channel.subscribe(self.context.clone())
+    }
+
+    /// Spawns `coro` once and returns a [`SharedCoro`] handle that can be stored and [`join`][
+    /// Fib::join]ed from multiple coroutines, each getting a clone of its output. Returns `None`
+    /// if `coro`'s params fail to initialize, same as [`par_or`](Self::par_or) and
+    /// [`par_first`](Self::par_first) silently drop a coroutine in that case.
+    pub fn shared<C, Marker, O>(&self, coro: C) -> Option<SharedCoro<O>>
+    where
+        C: UninitCoroutine<Marker>,
+        C::Coroutine: Coroutine<Output = O>,
+        O: Clone + Send + Sync + 'static,
+    {
+        shared::spawn(&self.context, coro)
+    }
+
+    /// Returns a future that resolves with a clone of `handle`'s output, once it (or another
+    /// awaiter) has driven it to completion.
+    pub fn join<'a, O: Clone + Send + Sync + 'static>(
+        &'a self,
+        handle: &'a SharedCoro<O>,
+    ) -> Join<'a, O> {
+        handle.join(&self.context)
+    }
 }
 
 impl CoroParam for Fib {
@@ -163,9 +248,11 @@ macro_rules! impl_coro_function {
         impl<Func, Fut, $($param: CoroParam),*> CoroutineParamFunction<fn($($param,)*) -> Fut> for Func
         where
             Func: FnOnce($($param),*) -> Fut + Send + 'static,
-            Fut: Future<Output = ()> + Send + 'static,
+            Fut: Future + Send + 'static,
+            Fut::Output: Send + 'static,
         {
 
+            type Output = Fut::Output;
             type Future = Fut;
             type Params = ($($param),*);
 
@@ -0,0 +1,126 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use bevy::ecs::component::ComponentTicks;
+use bevy::prelude::Component;
+use bevy::prelude::Entity;
+use bevy::prelude::World;
+
+use super::coro_param::{CoroAccess, CoroParam, ParamContext};
+use super::WaitingReason;
+
+/// A [`CoroParam`] that suspends until the owning [`Entity`]'s [`Component`] `T` is mutated,
+/// mirroring the "resolve on value change" semantics of a tokio `watch` channel.
+///
+/// Note that a coroutine with such a parameter will be canceled if the entity does not have the
+/// relevant component (or does not exist).
+pub struct OnChange<T: Component + Clone> {
+    owner: Entity,
+    context: ParamContext,
+    last_changed: u32,
+    // Whether the component's only recorded tick, as of `init`, was its insertion (i.e. it has
+    // never been mutated since). Consumed by the first poll, which then resolves immediately
+    // exactly once; how long ago the component was inserted is not taken into account.
+    pending_added: bool,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Component + Clone> CoroParam for OnChange<T> {
+    fn init(context: ParamContext, world: &mut World, access: &mut CoroAccess) -> Option<Self> {
+        let owner = context.owner;
+        let id = world.components().component_id::<T>()?;
+
+        if !access.add_read(owner, id) {
+            return None;
+        }
+
+        let ticks = world
+            .get_entity(owner)
+            .and_then(|entity| entity.get_change_ticks::<T>());
+
+        let last_changed = ticks.as_ref().map(last_changed_tick).unwrap_or(0);
+        let pending_added = ticks.is_some_and(|ticks| ticks.added_tick().get() == last_changed);
+
+        Some(Self {
+            owner,
+            context,
+            last_changed,
+            pending_added,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn is_valid(owner: Entity, world: &World) -> bool {
+        world
+            .get_entity(owner)
+            .is_some_and(|entity| entity.contains::<T>())
+    }
+}
+
+impl<T: Component + Clone> OnChange<T> {
+    /// Returns a future that resolves with a clone of `T` the next time it changes on the
+    /// owning entity. The first call resolves immediately if, when this parameter was
+    /// initialized, the component had not been mutated since it was inserted.
+    pub fn next(&mut self) -> OnChangeFuture<'_, T> {
+        OnChangeFuture { param: self }
+    }
+}
+
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct OnChangeFuture<'a, T: Component + Clone> {
+    param: &'a mut OnChange<T>,
+}
+
+impl<T: Component + Clone> Future for OnChangeFuture<'_, T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<T> {
+        let this = self.get_mut().param;
+
+        // Safety: We are being polled from within `FunctionCoroutine::resume`, which installs
+        // the world into the window for the duration of the poll.
+        let entity = unsafe {
+            this.context
+                .world_window
+                .world_cell()
+                .get_entity(this.owner)
+        };
+
+        let Some(entity) = entity else {
+            // The entity is gone; `Coroutine::is_valid` will cancel us on the next tick. We still
+            // have to yield a reason: `FunctionCoroutine::resume` expects every `Pending` poll to
+            // have sent one.
+            this.context.yield_channel.send(WaitingReason::NextTick);
+            return Poll::Pending;
+        };
+
+        let Some(ticks) = entity.get_change_ticks::<T>() else {
+            // The component is gone, same as the entity case above.
+            this.context.yield_channel.send(WaitingReason::NextTick);
+            return Poll::Pending;
+        };
+
+        if this.pending_added {
+            this.pending_added = false;
+            this.last_changed = last_changed_tick(&ticks);
+            let value = entity.get::<T>().unwrap().clone();
+            return Poll::Ready(value);
+        }
+
+        if this.last_changed != last_changed_tick(&ticks) {
+            this.last_changed = last_changed_tick(&ticks);
+            let value = entity.get::<T>().unwrap().clone();
+            return Poll::Ready(value);
+        }
+
+        this.context.yield_channel.send(WaitingReason::NextTick);
+        Poll::Pending
+    }
+}
+
+fn last_changed_tick(ticks: &ComponentTicks) -> u32 {
+    ticks.last_changed_tick().get()
+}
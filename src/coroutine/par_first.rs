@@ -0,0 +1,140 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+
+use bevy::prelude::World;
+use pin_project::pin_project;
+
+use super::coro_param::ParamContext;
+use super::par_or::ParOr;
+use super::CoroAccess;
+use super::CoroObject;
+use super::Coroutine;
+use super::CoroutineResult;
+use super::UninitCoroutine;
+use super::WaitingReason;
+
+/// The outcome of a [`Fib::par_first`](super::function_coroutine::Fib::par_first): which branch
+/// completed first, identified by its position in call order, and what it returned.
+pub struct Winner<O> {
+    pub index: usize,
+    pub value: O,
+}
+
+type Slot<O> = Arc<Mutex<Option<Winner<O>>>>;
+
+/// Wraps a coroutine so that its output is stashed (with its index) into a shared [`Slot`]
+/// instead of being reported through the normal [`Coroutine::resume`] channel, reporting
+/// completion as a plain `()`. This lets [`ParFirst`] race heterogeneous, typed coroutines while
+/// being built directly on top of [`ParOr`], which only ever races `Output = ()` coroutines.
+#[pin_project]
+struct Reporter<C: Coroutine> {
+    #[pin]
+    inner: C,
+    index: usize,
+    slot: Slot<C::Output>,
+}
+
+impl<C: Coroutine> Coroutine for Reporter<C> {
+    type Output = ();
+
+    fn resume(self: Pin<&mut Self>, world: &mut World) -> CoroutineResult<WaitingReason, ()> {
+        let this = self.project();
+
+        match this.inner.resume(world) {
+            CoroutineResult::Done(value) => {
+                *this.slot.lock().unwrap() = Some(Winner {
+                    index: *this.index,
+                    value,
+                });
+                CoroutineResult::Done(())
+            }
+            CoroutineResult::Yield(reason) => CoroutineResult::Yield(reason),
+        }
+    }
+
+    fn is_valid(self: Pin<&mut Self>, world: &World) -> bool {
+        self.project().inner.is_valid(world)
+    }
+
+    fn access(&self) -> &CoroAccess {
+        self.inner.access()
+    }
+}
+
+/// Returned by [`Fib::par_first`](super::function_coroutine::Fib::par_first): the coroutine
+/// equivalent of `futures::select!`. Resolves once any of the underlying coroutines finishes,
+/// with the [`Winner`] identifying which one and what it returned. Like [`ParOr`], ties are
+/// broken by resuming the coroutines top to bottom, and every other branch is dropped once a
+/// winner is picked.
+///
+/// Resolves with `None` if none of the coroutines passed to [`with`](Self::with) have a branch
+/// left to report from, i.e. every one of them failed [`CoroParam::init`](super::CoroParam::init)
+/// (the same condition under which [`ParOr`] itself resolves immediately, but `ParFirst` has no
+/// `Output` to hand back in that case).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct ParFirst<O> {
+    #[pin]
+    inner: ParOr,
+    slot: Slot<O>,
+    next_index: usize,
+}
+
+impl<O: Send + 'static> ParFirst<O> {
+    pub(crate) fn new(context: ParamContext) -> Self {
+        ParFirst {
+            inner: ParOr::new(context),
+            slot: Arc::new(Mutex::new(None)),
+            next_index: 0,
+        }
+    }
+
+    /// Adds a coroutine to this race. Its position in call order is what [`Winner::index`]
+    /// reports if it wins.
+    pub fn with<C, Marker>(mut self, coro: C) -> Self
+    where
+        C: UninitCoroutine<Marker>,
+        C::Coroutine: Coroutine<Output = O>,
+    {
+        let index = self.next_index;
+        self.next_index += 1;
+        let slot = self.slot.clone();
+
+        // Safety: We are getting polled right now, therefore we have exclusive world access.
+        unsafe {
+            let context = self.inner.context().clone();
+            if let Some(c) = coro.init(context.owner, context.world_window.world_cell().world_mut())
+            {
+                let reporter = Reporter {
+                    inner: c,
+                    index,
+                    slot,
+                };
+                self.inner = self
+                    .inner
+                    .with_coroutine(bevy::utils::synccell::SyncCell::new(Box::pin(reporter)));
+            }
+        }
+        self
+    }
+}
+
+impl<O: Send + 'static> Future for ParFirst<O> {
+    type Output = Option<Winner<O>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        match this.inner.poll(cx) {
+            // `ParOr` also resolves immediately when none of its branches survived `init`
+            // (nothing was ever added, or every one of them failed to initialize), in which case
+            // no `Reporter` ever ran and the slot stays empty.
+            Poll::Ready(()) => Poll::Ready(this.slot.lock().unwrap().take()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
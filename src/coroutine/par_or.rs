@@ -1,27 +1,27 @@
 use bevy::utils::synccell::SyncCell;
 
 use crate::coroutine::{CoroState, WaitingReason};
-use crate::prelude::Fib;
 
 use std::future::Future;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 
+use super::coro_param::ParamContext;
 use super::CoroObject;
 use super::UninitCoroutine;
 
 #[must_use = "futures do nothing unless you `.await` or poll them"]
-pub struct ParOr<'a> {
-    fib: &'a mut Fib,
+pub struct ParOr {
+    context: ParamContext,
     coroutines: Vec<CoroObject>,
     state: CoroState,
 }
 
-impl<'a> ParOr<'a> {
-    pub(crate) fn new(fib: &'a mut Fib) -> Self {
+impl ParOr {
+    pub(crate) fn new(context: ParamContext) -> Self {
         ParOr {
-            fib,
+            context,
             coroutines: vec![],
             state: CoroState::Running,
         }
@@ -35,17 +35,29 @@ impl<'a> ParOr<'a> {
         // Safety: We are getting polled right now, therefore we have exclusive world access.
         unsafe {
             if let Some(c) = coro.init(
-                self.fib.owner,
-                self.fib.world_window.world_cell().world_mut(),
+                self.context.owner,
+                self.context.world_window.world_cell().world_mut(),
             ) {
                 self.coroutines.push(SyncCell::new(Box::pin(c)));
             }
         }
         self
     }
+
+    /// Adds a coroutine that was already initialized, bypassing [`UninitCoroutine::init`]. Used
+    /// by combinators built on top of [`ParOr`], such as [`par_first`](
+    /// super::par_first::ParFirst), which need to wrap a coroutine before handing it over.
+    pub(crate) fn with_coroutine(mut self, coro: CoroObject) -> Self {
+        self.coroutines.push(coro);
+        self
+    }
+
+    pub(crate) fn context(&self) -> &ParamContext {
+        &self.context
+    }
 }
 
-impl Future for ParOr<'_> {
+impl Future for ParOr {
     type Output = ();
 
     fn poll(mut self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Self::Output> {
@@ -61,7 +73,7 @@ impl Future for ParOr<'_> {
                 }
                 self.state = CoroState::Halted;
                 let coroutines = std::mem::take(&mut self.coroutines);
-                self.fib
+                self.context
                     .yield_channel
                     .send(WaitingReason::ParOr { coroutines });
                 Poll::Pending
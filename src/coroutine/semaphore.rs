@@ -0,0 +1,170 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use super::coro_param::ParamContext;
+use super::WaitingReason;
+
+struct Waiter {
+    permits: u32,
+    satisfied: bool,
+    waker: Option<Waker>,
+}
+
+struct Inner {
+    available: u32,
+    queue: VecDeque<Arc<Mutex<Waiter>>>,
+}
+
+impl Inner {
+    /// Grants permits to waiters from the front of the queue, in order, stopping at the first
+    /// one that cannot yet be satisfied. This is the fairness invariant: a large request at the
+    /// head is never skipped in favor of smaller ones behind it.
+    fn drain_queue(&mut self) {
+        while let Some(front) = self.queue.front() {
+            let mut waiter = front.lock().unwrap();
+
+            if waiter.permits > self.available {
+                break;
+            }
+
+            self.available -= waiter.permits;
+            waiter.satisfied = true;
+            if let Some(waker) = waiter.waker.take() {
+                waker.wake();
+            }
+            drop(waiter);
+            self.queue.pop_front();
+        }
+    }
+}
+
+/// A permit-based semaphore used to throttle how many coroutines run a section of code
+/// concurrently, modeled on tokio's `Semaphore`.
+#[derive(Clone)]
+pub struct CoroSemaphore(Arc<Mutex<Inner>>);
+
+impl CoroSemaphore {
+    pub fn new(permits: u32) -> Self {
+        Self(Arc::new(Mutex::new(Inner {
+            available: permits,
+            queue: VecDeque::new(),
+        })))
+    }
+}
+
+/// Future returned by [`Fib::acquire`](super::function_coroutine::Fib::acquire), resolving with
+/// a [`Permit`] once enough permits are free.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Acquire<'a> {
+    context: &'a ParamContext,
+    sem: CoroSemaphore,
+    permits: u32,
+    waiter: Option<Arc<Mutex<Waiter>>>,
+}
+
+impl<'a> Acquire<'a> {
+    pub(crate) fn new(context: &'a ParamContext, sem: &CoroSemaphore, permits: u32) -> Self {
+        Self {
+            context,
+            sem: sem.clone(),
+            permits,
+            waiter: None,
+        }
+    }
+}
+
+impl Future for Acquire<'_> {
+    type Output = Permit;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Permit> {
+        let this = self.get_mut();
+        let mut inner = this.sem.0.lock().unwrap();
+
+        if let Some(waiter) = &this.waiter {
+            let mut waiter = waiter.lock().unwrap();
+            if waiter.satisfied {
+                drop(waiter);
+                // We're handing off the permits to the `Permit` we're about to return: clear
+                // `waiter` so `Drop` doesn't see `satisfied` and refund them a second time.
+                this.waiter = None;
+                return Poll::Ready(Permit {
+                    sem: this.sem.clone(),
+                    permits: this.permits,
+                });
+            }
+            waiter.waker = Some(cx.waker().clone());
+        } else {
+            let waiter = Arc::new(Mutex::new(Waiter {
+                permits: this.permits,
+                satisfied: false,
+                waker: Some(cx.waker().clone()),
+            }));
+            inner.queue.push_back(waiter.clone());
+            inner.drain_queue();
+
+            if waiter.lock().unwrap().satisfied {
+                return Poll::Ready(Permit {
+                    sem: this.sem.clone(),
+                    permits: this.permits,
+                });
+            }
+
+            this.waiter = Some(waiter);
+        }
+
+        drop(inner);
+        this.context.yield_channel.send(WaitingReason::Acquire {
+            sem: this.sem.clone(),
+            permits: this.permits,
+        });
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let Some(waiter) = self.waiter.take() else {
+            return;
+        };
+
+        let mut inner = self.sem.0.lock().unwrap();
+
+        if waiter.lock().unwrap().satisfied {
+            // We were granted permits but never turned them into a `Permit` to give them back on
+            // drop, because we were dropped while still parked (a losing `par_or`/`par_first`
+            // branch, a cancelled subtree, ...). Return them ourselves.
+            inner.available += self.permits;
+            inner.drain_queue();
+        } else {
+            inner.queue.retain(|w| !Arc::ptr_eq(w, &waiter));
+            // We may have been the head blocking smaller requests that now fit.
+            inner.drain_queue();
+        }
+    }
+}
+
+/// An RAII guard returned by [`Fib::acquire`](super::function_coroutine::Fib::acquire). The
+/// permits it represents are returned to the semaphore, unblocking the front of the wait queue if
+/// it can now be satisfied, when the guard is dropped.
+///
+/// The permits are returned at a well-defined point: wherever the coroutine drops the guard or
+/// lets it go out of scope. Nothing stops the guard from being held across an await point, in
+/// which case the permits stay taken until then.
+pub struct Permit {
+    sem: CoroSemaphore,
+    permits: u32,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut inner = self.sem.0.lock().unwrap();
+        inner.available += self.permits;
+        inner.drain_queue();
+    }
+}
@@ -0,0 +1,167 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use super::coro_param::ParamContext;
+use super::Coroutine;
+use super::CoroutineResult;
+use super::UninitCoroutine;
+use super::WaitingReason;
+
+/// A single slot a parked [`Join`] future registers itself in, so it can be found and woken again
+/// without ever needing more than one entry per awaiter, no matter how many times it is polled.
+type WakerSlot = Arc<Mutex<Option<Waker>>>;
+
+enum State<O> {
+    Running(Pin<Box<dyn Coroutine<Output = O> + Send>>),
+    Done(O),
+}
+
+struct Inner<O> {
+    state: Mutex<State<O>>,
+    wakers: Mutex<Vec<WakerSlot>>,
+    // The world change tick the inner coroutine was last resumed on, so that several awaiters
+    // polled within the same tick drive it forward exactly once instead of once each.
+    last_resumed_tick: Mutex<Option<u32>>,
+}
+
+/// A handle to a coroutine spawned once and shared between several awaiters, each getting a
+/// clone of its output, inspired by `futures::future::Shared`.
+///
+/// The inner coroutine is driven cooperatively: whichever awaiter is polled first in a given tick
+/// resumes it, yielding `WaitingReason::SharedJoin`; every other awaiter polled that same tick
+/// parks behind the same reason instead of resuming it again, so it always advances exactly once
+/// per tick no matter how many coroutines are joined to it. Awaiters that [`join`](
+/// SharedCoro::join) after completion resolve immediately with the cached value.
+pub struct SharedCoro<O: Clone + Send + Sync + 'static>(Arc<Inner<O>>);
+
+impl<O: Clone + Send + Sync + 'static> Clone for SharedCoro<O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<O: Clone + Send + Sync + 'static> SharedCoro<O> {
+    pub(crate) fn new(coroutine: Pin<Box<dyn Coroutine<Output = O> + Send>>) -> Self {
+        Self(Arc::new(Inner {
+            state: Mutex::new(State::Running(coroutine)),
+            wakers: Mutex::new(Vec::new()),
+            last_resumed_tick: Mutex::new(None),
+        }))
+    }
+
+    /// Returns a future that resolves with a clone of the shared coroutine's output, driving it
+    /// if nobody has yet this tick.
+    pub fn join<'a>(&'a self, context: &'a ParamContext) -> Join<'a, O> {
+        Join {
+            context,
+            handle: self,
+            slot: None,
+        }
+    }
+
+    /// An id unique to this handle's shared storage (stable across clones), used to tell apart
+    /// distinct `SharedCoro<O>` instances in [`WaitingReason::SharedJoin`], which can't carry a
+    /// generic `O` and so can't be keyed on `TypeId` alone.
+    fn id(&self) -> usize {
+        Arc::as_ptr(&self.0) as usize
+    }
+}
+
+/// Future returned by [`SharedCoro::join`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Join<'a, O: Clone + Send + Sync + 'static> {
+    context: &'a ParamContext,
+    handle: &'a SharedCoro<O>,
+    slot: Option<WakerSlot>,
+}
+
+impl<O: Clone + Send + Sync + 'static> Future for Join<'_, O> {
+    type Output = O;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<O> {
+        let this = self.get_mut();
+        let mut state = this.handle.0.state.lock().unwrap();
+
+        if let State::Done(value) = &*state {
+            return Poll::Ready(value.clone());
+        }
+
+        // Register (or update) a single slot for this awaiter, rather than pushing a new waker
+        // on every poll: being re-polled every tick while still pending must not grow `wakers`
+        // without bound.
+        match &this.slot {
+            Some(slot) => *slot.lock().unwrap() = Some(cx.waker().clone()),
+            None => {
+                let slot: WakerSlot = Arc::new(Mutex::new(Some(cx.waker().clone())));
+                this.handle.0.wakers.lock().unwrap().push(slot.clone());
+                this.slot = Some(slot);
+            }
+        }
+
+        // Safety: We are being polled from within `FunctionCoroutine::resume`, which guarantees
+        // exclusive world access for the duration of the poll.
+        let world = unsafe { this.context.world_window.world_cell().world_mut() };
+        let this_tick = world.change_tick();
+
+        let mut last_resumed_tick = this.handle.0.last_resumed_tick.lock().unwrap();
+
+        if *last_resumed_tick == Some(this_tick) {
+            // Some other awaiter already drove the shared coroutine forward this tick: with N
+            // awaiters polled the same tick, it must still only be resumed once, or anything
+            // timing-sensitive inside it would advance N steps per real tick.
+            drop(last_resumed_tick);
+            drop(state);
+            this.context
+                .yield_channel
+                .send(WaitingReason::SharedJoin(this.handle.id()));
+            return Poll::Pending;
+        }
+
+        *last_resumed_tick = Some(this_tick);
+        drop(last_resumed_tick);
+
+        let State::Running(coroutine) = &mut *state else {
+            unreachable!("checked above")
+        };
+
+        match coroutine.as_mut().resume(world) {
+            CoroutineResult::Done(value) => {
+                *state = State::Done(value.clone());
+                drop(state);
+
+                for slot in this.handle.0.wakers.lock().unwrap().drain(..) {
+                    if let Some(waker) = slot.lock().unwrap().take() {
+                        waker.wake();
+                    }
+                }
+
+                Poll::Ready(value)
+            }
+            CoroutineResult::Yield(_reason) => {
+                drop(state);
+                this.context
+                    .yield_channel
+                    .send(WaitingReason::SharedJoin(this.handle.id()));
+                Poll::Pending
+            }
+        }
+    }
+}
+
+pub(crate) fn spawn<C, Marker, O>(context: &ParamContext, coro: C) -> Option<SharedCoro<O>>
+where
+    C: UninitCoroutine<Marker>,
+    C::Coroutine: Coroutine<Output = O>,
+    O: Clone + Send + Sync + 'static,
+{
+    // Safety: We are getting polled right now, therefore we have exclusive world access.
+    let coroutine =
+        unsafe { coro.init(context.owner, context.world_window.world_cell().world_mut())? };
+
+    Some(SharedCoro::new(Box::pin(coroutine)))
+}
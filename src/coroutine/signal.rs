@@ -0,0 +1,136 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use bevy::prelude::Component;
+use bevy::prelude::Resource;
+
+use super::coro_param::ParamContext;
+use super::WaitingReason;
+
+/// A single slot a parked [`NextSignal`] future registers itself in, so it can be found and woken
+/// again without ever needing more than one entry per awaiter, no matter how many times it is
+/// polled.
+type WakerSlot = Arc<Mutex<Option<Waker>>>;
+
+struct Inner<T> {
+    value: Option<T>,
+    parked: Vec<WakerSlot>,
+}
+
+impl<T> Default for Inner<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            parked: Vec::new(),
+        }
+    }
+}
+
+/// A one-slot async signalling primitive, inspired by embassy-sync's `Signal` and tokio's
+/// `Notify`, that lets coroutines wake each other without polling a component.
+///
+/// Store it as a Bevy [`Resource`] or [`Component`] and award coroutines a reference to it;
+/// [`Fib::next_signal`](super::function_coroutine::Fib::next_signal) parks until [`signal`](
+/// Signal::signal) is called. A `signal()` call before the value is consumed overwrites the slot
+/// (latest wins), and an awaiter that arrives after a `signal()` consumes it immediately.
+#[derive(Component, Resource)]
+pub struct Signal<T: Send + Sync + 'static>(Arc<Mutex<Inner<T>>>);
+
+impl<T: Send + Sync + 'static> Default for Signal<T> {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(Inner::default())))
+    }
+}
+
+impl<T: Send + Sync + 'static> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Send + Sync + 'static> Signal<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes `value` into the slot, overwriting any unconsumed value, and wakes every parked
+    /// awaiter.
+    pub fn signal(&self, value: T) {
+        let mut inner = self.0.lock().unwrap();
+        inner.value = Some(value);
+
+        for slot in inner.parked.drain(..) {
+            if let Some(waker) = slot.lock().unwrap().take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// An id unique to this signal's shared storage (stable across clones), used to tell apart
+    /// distinct `Signal<T>` instances in [`WaitingReason::Signal`], which can't carry a generic
+    /// `T` and so can't be keyed on `TypeId` alone.
+    fn id(&self) -> usize {
+        Arc::as_ptr(&self.0) as usize
+    }
+}
+
+/// Future returned by [`Fib::next_signal`](super::function_coroutine::Fib::next_signal).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NextSignal<'a, T: Send + Sync + 'static> {
+    context: &'a ParamContext,
+    signal: &'a Signal<T>,
+    slot: Option<WakerSlot>,
+}
+
+impl<'a, T: Send + Sync + 'static> NextSignal<'a, T> {
+    pub(crate) fn new(context: &'a ParamContext, signal: &'a Signal<T>) -> Self {
+        Self {
+            context,
+            signal,
+            slot: None,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Future for NextSignal<'_, T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<T> {
+        let this = self.get_mut();
+        let mut inner = this.signal.0.lock().unwrap();
+
+        if let Some(value) = inner.value.take() {
+            return Poll::Ready(value);
+        }
+
+        // Register (or update) a single slot for this awaiter, rather than pushing a new waker
+        // on every poll: being re-polled every tick while still pending must not grow `parked`
+        // without bound.
+        match &this.slot {
+            Some(slot) => {
+                *slot.lock().unwrap() = Some(cx.waker().clone());
+                // `signal()` drains `parked`; if another awaiter consumed that value, list our
+                // slot again so the next `signal()` still finds it.
+                if !inner.parked.iter().any(|s| Arc::ptr_eq(s, slot)) {
+                    inner.parked.push(slot.clone());
+                }
+            }
+            None => {
+                let slot: WakerSlot = Arc::new(Mutex::new(Some(cx.waker().clone())));
+                inner.parked.push(slot.clone());
+                this.slot = Some(slot);
+            }
+        }
+        drop(inner);
+
+        this.context
+            .yield_channel
+            .send(WaitingReason::Signal(this.signal.id()));
+        Poll::Pending
+    }
+}